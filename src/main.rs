@@ -1,6 +1,8 @@
 // This is a comment. Line comments look like this...
 // and extend multiple lines like this.
 
+/* Block comments also exist /* and can be nested */ like this one. */
+
 /// Documentation comments look like this and support markdown notation.
 /// # Examples
 ///
@@ -25,8 +27,34 @@ fn add_uints(x: i32, y: i32) -> i32 {
     return x + y;
 }
 
+// A function returning a `Result` can use `?` to propagate an error to its
+// caller instead of matching on it by hand. `?` unwraps the `Ok` value, or
+// returns early with the `Err` value.
+fn parse_and_double(input: &str) -> Result<i32, std::num::ParseIntError> {
+    let n: i32 = input.parse()?;
+    Ok(n * 2)
+}
+
 // Main function
 fn main() {
+    ///////////////////////
+    // 0. Lexical basics //
+    ///////////////////////
+
+    // Numeric literals can carry an explicit type suffix...
+    let thirteen = 13i32;
+    let one_point_three = 1.3f64;
+
+    // ...and underscores as visual separators, which the compiler ignores
+    let one_million = 1_000_000;
+    println!("{} {} {}", thirteen, one_point_three, one_million);
+
+    // Attributes attach metadata to the item that follows them.
+    // `#[allow(dead_code)]` silences the "never used" warning that would
+    // otherwise fire for this function
+    #[allow(dead_code)]
+    fn unused_helper() {}
+
     // Immutable bindings
     // <var>: <type> = <value>
     let x: i32 = 42;
@@ -98,6 +126,9 @@ fn main() {
     //////////////
 
     // Struct
+    // `#[derive(Debug)]` generates a `Debug` implementation, so values of
+    // this type can be printed with `{:?}`
+    #[derive(Debug)]
     struct Point {
         x: i32,
         y: i32,
@@ -177,7 +208,7 @@ fn main() {
     }
 
     let another_point = Point { x: 1, y: 2 };
-    println!("{:?}", another_point.multiply(2).get_x()); // 2
+    println!("{:?}", another_point.multiply(2)); // Point { x: 2, y: 4 }
 
     /////////////////////////
     // 3. Pattern matching //
@@ -206,6 +237,42 @@ fn main() {
             println!("The second number is Nothing!"),
     }
 
+    // `if let` matches a single pattern, which is more concise than `match`
+    // when you only care about one case
+    if let OptionalI32::AnI32(n) = foo {
+        println!("it’s an i32: {}", n);
+    } else {
+        println!("it’s nothing!");
+    }
+
+    // `while let` keeps looping for as long as the pattern matches, which is
+    // handy for draining a collection
+    let mut stack = vec![1, 2, 3];
+    while let Some(top) = stack.pop() {
+        println!("{}", top); // 3, 2, 1
+    }
+
+    // Range patterns match an inclusive range of values
+    let n = 4;
+    match n {
+        1..=5 => println!("between 1 and 5"),
+        _ => println!("out of range"),
+    }
+
+    // The `@` operator binds a name to a value while also testing it against
+    // a pattern
+    match n {
+        bound @ 1..=10 => println!("got {}, which is between 1 and 10", bound),
+        _ => println!("out of range"),
+    }
+
+    // `|` matches against any of several patterns
+    match n {
+        1 | 3 | 5 => println!("small odd number"),
+        2 | 4 | 6 => println!("small even number"),
+        _ => println!("something else"),
+    }
+
     /////////////////////
     // 4. Control flow //
     /////////////////////
@@ -280,6 +347,227 @@ fn main() {
     *ref_var2 += 2;
 
     println!("{}", *ref_var2); // 6
+
+    // Non-lexical lifetimes: a borrow ends at its last use, not at the end
+    // of its enclosing scope. `ref_var3` is last used on the line below, so
+    // it's fine to take a new `&mut` borrow of `var3` right after
+    let mut var3 = 4;
+    let ref_var3 = &var3;
+    println!("{}", *ref_var3); // 4
+
+    let ref_var3_mut = &mut var3; // this compiles: `ref_var3`'s borrow already ended
+    *ref_var3_mut += 1;
+    println!("{}", *ref_var3_mut); // 5
+
+    // Lifetime parameters describe how long references are valid for. Here,
+    // `'a` says the returned reference is valid only while both inputs are
+    fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+        if x.len() > y.len() { x } else { y }
+    }
+
+    println!("{}", longest("hello", "world!")); // world!
+
+    // A struct can hold a reference, as long as its lifetime parameter ties
+    // the reference's lifetime to the struct's
+    struct Holder<'a> {
+        value: &'a str,
+    }
+
+    let held = String::from("held value");
+    let holder = Holder { value: &held };
+    println!("{}", holder.value); // held value
+
+    /////////////////////////////////////
+    // 6. Closures & iterator adapters //
+    /////////////////////////////////////
+
+    // Closures are anonymous functions, written `|args| body`. Types are
+    // usually inferred, so you rarely need to annotate them.
+    let add_one = |x: i32| x + 1;
+    println!("{}", add_one(5)); // 6
+
+    // Closures capture their environment, unlike regular functions
+    let factor = 10;
+    let multiply_by_factor = |x: i32| x * factor;
+    println!("{}", multiply_by_factor(3)); // 30
+
+    // `move` forces the closure to take ownership of the variables it
+    // captures, instead of borrowing them
+    let name = String::from("Rust");
+    let greet = move || println!("Hello, {}!", name);
+    greet(); // Hello, Rust!
+
+    // Closures implement one (or more) of three traits, depending on how
+    // they use their captured variables:
+    // - `FnOnce`: can be called once; consumes captured variables
+    // - `FnMut`: can be called multiple times; may mutate captured variables
+    // - `Fn`: can be called multiple times; only borrows captured variables
+    fn call_with_one<F: Fn(i32) -> i32>(f: F) -> i32 {
+        f(1)
+    }
+    println!("{}", call_with_one(add_one)); // 2
+
+    // Iterator adapters let you build up computations on iterators without
+    // allocating intermediate collections until `.collect()` is called
+    let doubled: Vec<i32> = vector.iter().map(|x| x * 2).collect();
+    println!("{:?}", doubled); // [2, 4, 6, 8, 10]
+
+    let evens: Vec<&i32> = vector.iter().filter(|x| **x % 2 == 0).collect();
+    println!("{:?}", evens); // [2, 4]
+
+    let sum = vector.iter().fold(0, |acc, x| acc + x);
+    println!("{}", sum); // 15
+
+    // Adapters chain together, and `collect` can target any type that
+    // implements `FromIterator` – here the turbofish asks for a `Vec` and
+    // leaves its element type to inference
+    let doubled_evens = vector
+        .iter()
+        .filter(|x| **x % 2 == 0)
+        .map(|x| x * 2)
+        .collect::<Vec<_>>();
+    println!("{:?}", doubled_evens); // [4, 8]
+
+    /////////////////////////////////////////////
+    // 7. Error handling: Option, Result and ? //
+    /////////////////////////////////////////////
+
+    // `Option<T>` represents a value that might be absent, as an alternative
+    // to the hand-rolled `OptionalI32` above
+    let some_number: Option<i32> = Some(5);
+    let no_number: Option<i32> = None;
+
+    match some_number {
+        Some(n) => println!("got a number: {}", n),
+        None => println!("got nothing"),
+    }
+
+    // `.unwrap_or()` provides a default for the `None` case
+    println!("{}", no_number.unwrap_or(0)); // 0
+
+    // `.map()` transforms the contained value, if there is one
+    let doubled_number = some_number.map(|n| n * 2);
+    println!("{:?}", doubled_number); // Some(10)
+
+    // `Result<T, E>` represents either success (`Ok`) or failure (`Err`)
+    let good: Result<i32, &str> = Ok(42);
+    let bad: Result<i32, &str> = Err("something went wrong");
+
+    match good {
+        Ok(n) => println!("success: {}", n),
+        Err(e) => println!("failure: {}", e),
+    }
+
+    println!("{}", bad.unwrap_or(-1)); // -1
+
+    // The `?` operator (used inside `parse_and_double`, defined above) keeps
+    // error propagation terse – it's equivalent to a match that returns
+    // early on `Err`
+    match parse_and_double("21") {
+        Ok(n) => println!("{}", n), // 42
+        Err(e) => println!("error: {}", e),
+    }
+
+    /////////////////////////////////////////////////////
+    // 8. Concurrency: threads, channels, shared state //
+    /////////////////////////////////////////////////////
+
+    // `std::thread::spawn` runs a closure on a new OS thread and returns a
+    // `JoinHandle` that can be used to wait for its result
+    let handle = std::thread::spawn(|| {
+        println!("Hello from a thread!");
+        42
+    });
+
+    // `.join()` blocks until the thread finishes, returning its result
+    let thread_result = handle.join().unwrap();
+    println!("{}", thread_result); // 42
+
+    // `mpsc` channels let threads communicate by sending values instead of
+    // sharing memory directly
+    let (tx, rx) = std::sync::mpsc::channel();
+
+    std::thread::spawn(move || {
+        tx.send("message from another thread").unwrap();
+    });
+
+    println!("{}", rx.recv().unwrap());
+
+    // `Arc<Mutex<T>>` shares ownership of mutable data across threads:
+    // `Arc` (atomic reference count) allows multiple owners, and `Mutex`
+    // ensures only one thread mutates the data at a time
+    let counter = std::sync::Arc::new(std::sync::Mutex::new(0));
+    let mut handles = vec![];
+
+    for _ in 0..10 {
+        let counter = std::sync::Arc::clone(&counter);
+        let handle = std::thread::spawn(move || {
+            let mut n = counter.lock().unwrap();
+            *n += 1;
+        });
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    println!("{}", *counter.lock().unwrap()); // 10
+
+    ///////////////////////////////////////
+    // 9. Trait objects & generic bounds //
+    ///////////////////////////////////////
+
+    // A trait bound on a generic restricts what types `T` may be, and lets
+    // you call trait methods on `x` inside the function
+    fn double<T: Multiply>(x: T) -> T {
+        x.multiply(2)
+    }
+
+    // `where` clauses say the same thing, but read better once there are
+    // several bounds or more complex types involved
+    fn double_where<T>(x: T) -> T
+    where
+        T: Multiply,
+    {
+        x.multiply(2)
+    }
+
+    // Static dispatch: the compiler monomorphizes `double`/`double_where`
+    // into a separate copy for each concrete `T`, so the call is resolved
+    // (and can be inlined) at compile time – there's no runtime overhead
+    let doubled_point = double(Point { x: 1, y: 2 });
+    println!("{}", doubled_point.get_x()); // 2
+    println!("{}", double_where(Point { x: 2, y: 3 }).get_x()); // 4
+
+    // A default method body is used unless the implementor overrides it.
+    // Unlike `Multiply`, whose method returns `Self`, `Greet` never mentions
+    // `Self` outside the receiver, which keeps it usable as a trait object below
+    trait Greet {
+        fn name(&self) -> String;
+
+        fn greet(&self) -> String {
+            format!("Hello, {}!", self.name())
+        }
+    }
+
+    impl Greet for Point {
+        fn name(&self) -> String {
+            format!("Point({}, {})", self.x, self.y)
+        }
+    }
+
+    println!("{}", Point { x: 5, y: 6 }.greet()); // Hello, Point(5, 6)!
+
+    // Dynamic dispatch: `Box<dyn Greet>` (or `&dyn Greet`) erases the
+    // concrete type behind a vtable, so the same variable can hold any
+    // `Greet` implementor and the call to `.greet()` is resolved at runtime
+    fn greet_dyn(x: &dyn Greet) -> String {
+        x.greet()
+    }
+
+    let boxed_point: Box<dyn Greet> = Box::new(Point { x: 3, y: 4 });
+    println!("{}", greet_dyn(&*boxed_point)); // Hello, Point(3, 4)!
 }
 
 // Adapted from https://learnxinyminutes.com/docs/rust/